@@ -11,11 +11,78 @@ use ratatui::{
     style::{Color, Style},
     symbols,
 };
-use open_meteo_rs::forecast::{ForecastResult, Options, TemperatureUnit};
+use open_meteo_rs::forecast::{ForecastResult, Options, TemperatureUnit, WindSpeedUnit};
 use log::{info, error, debug};
 use simplelog::{Config, WriteLogger, LevelFilter};
 use std::fs::File;
-use chrono::NaiveDate;
+use std::sync::Arc;
+use chrono::{Local, NaiveDate};
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+mod weather;
+use weather::{geocode, locate_by_ip};
+
+/// The chart variables that can be cycled through at runtime with Tab/arrow keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Metric {
+    TempMax,
+    Precipitation,
+    WindMax,
+    Humidity,
+}
+
+impl Metric {
+    /// Returns the next metric in the cycle, wrapping back to the first.
+    fn next(self) -> Self {
+        match self {
+            Metric::TempMax => Metric::Precipitation,
+            Metric::Precipitation => Metric::WindMax,
+            Metric::WindMax => Metric::Humidity,
+            Metric::Humidity => Metric::TempMax,
+        }
+    }
+
+    /// The Open-Meteo daily field requested for this metric.
+    fn field_name(self) -> &'static str {
+        match self {
+            Metric::TempMax => "temperature_2m_max",
+            Metric::Precipitation => "precipitation_sum",
+            Metric::WindMax => "wind_speed_10m_max",
+            Metric::Humidity => "relative_humidity_2m_max",
+        }
+    }
+
+    /// The dataset/axis label for this metric, without its unit.
+    fn label(self) -> &'static str {
+        match self {
+            Metric::TempMax => "Temp",
+            Metric::Precipitation => "Precipitation",
+            Metric::WindMax => "Wind",
+            Metric::Humidity => "Humidity",
+        }
+    }
+
+    /// The line color used to render this metric.
+    fn color(self) -> Color {
+        match self {
+            Metric::TempMax => Color::Magenta,
+            Metric::Precipitation => Color::Blue,
+            Metric::WindMax => Color::Green,
+            Metric::Humidity => Color::Yellow,
+        }
+    }
+}
+
+/// The result of polling for terminal input during one frame.
+enum InputAction {
+    /// No relevant key was pressed.
+    None,
+    /// The user requested to quit.
+    Quit,
+    /// The user requested to cycle to the next metric.
+    NextMetric,
+}
 
 /// Main entry point of the application.
 ///
@@ -39,38 +106,127 @@ async fn main() -> io::Result<()> {
 
     info!("Starting the application...");
 
-    // Parse command line arguments for location
-    let args: Vec<String> = env::args().collect();
-    let (lat, lng) = if args.len() == 3 {
-        let lat = args[1].parse().unwrap_or(40.7128); // Default to NYC latitude if parse fails
-        let lng = args[2].parse().unwrap_or(-74.0060); // Default to NYC longitude if parse fails
-        (lat, lng)
-    } else {
-        (40.7128, -74.0060) // Default to New York City coordinates
-    };
+    // Parse command line arguments for location. Two numeric args are treated as
+    // lat/lng directly; any other non-empty args are treated as a place name and
+    // geocoded; with no args the location is looked up by IP.
+    let mut args: Vec<String> = env::args().collect();
 
-    info!("Using location: Latitude {}, Longitude {}", lat, lng);
+    // Pull out `--format <normal|json|csv>` before parsing the location args, so the
+    // flag can appear anywhere on the command line.
+    let mut format = "normal".to_string();
+    if let Some(pos) = args.iter().position(|a| a == "--format") {
+        match args.get(pos + 1).cloned() {
+            Some(value) => {
+                format = value;
+                args.drain(pos..=pos + 1);
+            }
+            None => {
+                error!("--format requires a value, ignoring");
+                args.drain(pos..=pos);
+            }
+        }
+    }
+    info!("Output format: {}", format);
 
-    // Enable raw mode for the terminal to capture input events
-    enable_raw_mode()?;
-    stdout().execute(EnterAlternateScreen)?;
-    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+    // Pull out `--temperature-unit <celsius|fahrenheit>` and
+    // `--wind-speed-unit <kmh|ms|mph|knots>`, same as `--format` above. Kept as raw
+    // strings (rather than parsed immediately) so the background refresh task can
+    // rebuild fresh `Options` on every poll without needing to clone the crate's enums.
+    let mut temperature_unit_arg = "fahrenheit".to_string();
+    if let Some(pos) = args.iter().position(|a| a == "--temperature-unit") {
+        match args.get(pos + 1).cloned() {
+            Some(value) => {
+                temperature_unit_arg = value;
+                args.drain(pos..=pos + 1);
+            }
+            None => {
+                error!("--temperature-unit requires a value, ignoring");
+                args.drain(pos..=pos);
+            }
+        }
+    }
 
-    let client = open_meteo_rs::Client::new();
-    let mut opts = Options::default();
+    let mut wind_speed_unit_arg = "kmh".to_string();
+    if let Some(pos) = args.iter().position(|a| a == "--wind-speed-unit") {
+        match args.get(pos + 1).cloned() {
+            Some(value) => {
+                wind_speed_unit_arg = value;
+                args.drain(pos..=pos + 1);
+            }
+            None => {
+                error!("--wind-speed-unit requires a value, ignoring");
+                args.drain(pos..=pos);
+            }
+        }
+    }
 
-    // Set location and options
-    opts.location = open_meteo_rs::Location { lat, lng };
-    opts.forecast_days = Some(10);
+    let temperature_suffix = temperature_unit_suffix(&parse_temperature_unit(&temperature_unit_arg)).to_string();
+    let wind_speed_suffix = wind_speed_unit_suffix(&parse_wind_speed_unit(&wind_speed_unit_arg)).to_string();
+    info!("Temperature unit: {}, wind speed unit: {}", temperature_suffix, wind_speed_suffix);
+
+    // Pull out `--refresh <seconds>` to enable periodic background re-fetching.
+    let mut refresh_interval_secs: Option<u64> = None;
+    if let Some(pos) = args.iter().position(|a| a == "--refresh") {
+        match args.get(pos + 1).cloned() {
+            Some(value) => {
+                match value.parse::<u64>() {
+                    Ok(seconds) if seconds > 0 => refresh_interval_secs = Some(seconds),
+                    _ => error!("Invalid --refresh value \"{}\", ignoring", value),
+                }
+                args.drain(pos..=pos + 1);
+            }
+            None => {
+                error!("--refresh requires a value, ignoring");
+                args.drain(pos..=pos);
+            }
+        }
+    }
+
+    let (lat, lng, location_label) = if args.len() == 3
+        && args[1].parse::<f64>().is_ok()
+        && args[2].parse::<f64>().is_ok()
+    {
+        let lat: f64 = args[1].parse().unwrap();
+        let lng: f64 = args[2].parse().unwrap();
+        info!("Using coordinates from args: Latitude {}, Longitude {}", lat, lng);
+        (lat, lng, format!("{:.2}, {:.2}", lat, lng))
+    } else if args.len() >= 2 {
+        let query = args[1..].join(" ");
+        info!("Geocoding place name from args: {}", query);
+        match geocode(&query).await {
+            Ok((lat, lng, label)) => {
+                info!("Resolved \"{}\" to Latitude {}, Longitude {} ({})", query, lat, lng, label);
+                (lat, lng, label)
+            }
+            Err(e) => {
+                // Mirrors the forecast-fetch-failure handling below: this exits with a
+                // plain stderr error before raw mode/the alternate screen are entered,
+                // not a message rendered inside the TUI itself.
+                error!("Failed to geocode \"{}\": {}", query, e);
+                return Err(io::Error::new(io::ErrorKind::Other, format!("Could not find location \"{}\"", query)));
+            }
+        }
+    } else {
+        info!("No location given, attempting to autolocate by IP...");
+        match locate_by_ip().await {
+            Ok((lat, lng, label)) => {
+                info!("Autolocated via IP to Latitude {}, Longitude {} ({})", lat, lng, label);
+                (lat, lng, label)
+            }
+            Err(e) => {
+                error!("IP autolocation failed, falling back to New York City: {}", e);
+                (40.7128, -74.0060, "New York City".to_string()) // Default to New York City coordinates
+            }
+        }
+    };
 
-    // Set temperature unit to Fahrenheit
-    opts.temperature_unit = Some(TemperatureUnit::Fahrenheit);
+    info!("Using location: Latitude {}, Longitude {} ({})", lat, lng, location_label);
 
-    // Request maximum daily temperature
-    opts.daily.push("temperature_2m_max".into());
+    let client = open_meteo_rs::Client::new();
 
     // Fetch the forecast
     info!("Fetching weather forecast...");
+    let opts = build_options(lat, lng, &temperature_unit_arg, &wind_speed_unit_arg);
     let res: ForecastResult = match client.forecast(opts).await {
         Ok(forecast) => {
             info!("Forecast successfully retrieved: {:#?}", forecast);
@@ -82,10 +238,81 @@ async fn main() -> io::Result<()> {
         }
     };
 
+    // Headless export modes skip the TUI entirely and print the daily series to stdout.
+    // `print_export` is the single place that validates `format`; an unrecognized
+    // value falls through to the normal TUI instead of exiting silently.
+    if format != "normal" {
+        let temp_data = extract_temperature_data(&res);
+        if print_export(&temp_data, &format) {
+            info!("Printed {} export and exiting.", format);
+            return Ok(());
+        }
+    }
+
+    let shared_forecast = Arc::new(Mutex::new(SharedForecast {
+        result: res,
+        updated_at: Local::now().format("%H:%M").to_string(),
+    }));
+
+    // If a refresh interval was given, periodically re-fetch the forecast in the
+    // background and swap it into the shared state the render loop reads from. A
+    // failed poll keeps the last good data rather than tearing down the TUI.
+    if let Some(interval_secs) = refresh_interval_secs {
+        let shared_forecast = Arc::clone(&shared_forecast);
+        let temperature_unit_arg = temperature_unit_arg.clone();
+        let wind_speed_unit_arg = wind_speed_unit_arg.clone();
+        info!("Auto-refresh enabled every {} seconds", interval_secs);
+        tokio::spawn(async move {
+            let client = open_meteo_rs::Client::new();
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(interval_secs)).await;
+                info!("Refreshing forecast in the background...");
+                let opts = build_options(lat, lng, &temperature_unit_arg, &wind_speed_unit_arg);
+                match client.forecast(opts).await {
+                    Ok(forecast) => {
+                        let mut shared = shared_forecast.lock().await;
+                        shared.result = forecast;
+                        shared.updated_at = Local::now().format("%H:%M").to_string();
+                        info!("Forecast refreshed at {}", shared.updated_at);
+                    }
+                    Err(e) => {
+                        error!("Background refresh failed, keeping last good data: {}", e);
+                    }
+                }
+            }
+        });
+    }
+
+    // Enable raw mode for the terminal to capture input events
+    enable_raw_mode()?;
+    stdout().execute(EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+
+    let mut current_metric = Metric::TempMax;
     let mut should_quit = false;
     while !should_quit {
-        terminal.draw(|f| ui(f, &res))?;
-        should_quit = handle_events()?;
+        {
+            let shared = shared_forecast.lock().await;
+            terminal.draw(|f| {
+                ui(
+                    f,
+                    &shared.result,
+                    &location_label,
+                    current_metric,
+                    &temperature_suffix,
+                    &wind_speed_suffix,
+                    &shared.updated_at,
+                )
+            })?;
+        }
+        match handle_events()? {
+            InputAction::Quit => should_quit = true,
+            InputAction::NextMetric => {
+                current_metric = current_metric.next();
+                info!("Switched chart metric to {:?}", current_metric);
+            }
+            InputAction::None => {}
+        }
     }
 
     // Restore the terminal state
@@ -97,25 +324,168 @@ async fn main() -> io::Result<()> {
 
 /// Handles terminal input events.
 ///
-/// Listens for keyboard input and checks if the 'q' key is pressed to exit the application.
+/// Listens for keyboard input and checks for the quit key ('q') or the metric-switching
+/// keys (Tab, Left, Right).
 ///
 /// # Returns
 ///
-/// Returns `Ok(true)` if the 'q' key is pressed, indicating that the application should quit.
-/// Otherwise, returns `Ok(false)`.
-fn handle_events() -> io::Result<bool> {
+/// Returns the `InputAction` corresponding to the key pressed, or `InputAction::None` if
+/// no relevant key was pressed during this poll.
+fn handle_events() -> io::Result<InputAction> {
     if event::poll(std::time::Duration::from_millis(50))? {
         if let Event::Key(key) = event::read()? {
-            if key.kind == event::KeyEventKind::Press && key.code == KeyCode::Char('q') {
-                info!("Received quit command.");
-                return Ok(true);
+            if key.kind == event::KeyEventKind::Press {
+                match key.code {
+                    KeyCode::Char('q') => {
+                        info!("Received quit command.");
+                        return Ok(InputAction::Quit);
+                    }
+                    KeyCode::Tab | KeyCode::Left | KeyCode::Right => {
+                        info!("Received metric switch command.");
+                        return Ok(InputAction::NextMetric);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(InputAction::None)
+}
+
+/// The latest forecast data shown by the render loop, refreshed in place by the
+/// background auto-refresh task when `--refresh` is given.
+struct SharedForecast {
+    result: ForecastResult,
+    updated_at: String,
+}
+
+/// Builds the `Options` used to request the forecast, including every daily field
+/// needed across all `Metric` variants and the chosen temperature/wind speed units.
+fn build_options(lat: f64, lng: f64, temperature_unit_arg: &str, wind_speed_unit_arg: &str) -> Options {
+    let mut opts = Options::default();
+
+    opts.location = open_meteo_rs::Location { lat, lng };
+    opts.forecast_days = Some(10);
+    opts.temperature_unit = Some(parse_temperature_unit(temperature_unit_arg));
+    opts.wind_speed_unit = Some(parse_wind_speed_unit(wind_speed_unit_arg));
+
+    opts.daily.push("temperature_2m_max".into());
+    opts.daily.push("temperature_2m_min".into());
+    opts.daily.push("precipitation_sum".into());
+    opts.daily.push("wind_speed_10m_max".into());
+    opts.daily.push("relative_humidity_2m_max".into());
+
+    opts
+}
+
+/// Parses the `--temperature-unit` flag value, defaulting to Fahrenheit on an
+/// unrecognized value.
+fn parse_temperature_unit(arg: &str) -> TemperatureUnit {
+    match arg {
+        "celsius" => TemperatureUnit::Celsius,
+        "fahrenheit" => TemperatureUnit::Fahrenheit,
+        other => {
+            error!("Unknown temperature unit \"{}\", defaulting to fahrenheit", other);
+            TemperatureUnit::Fahrenheit
+        }
+    }
+}
+
+/// Parses the `--wind-speed-unit` flag value, defaulting to km/h on an
+/// unrecognized value.
+fn parse_wind_speed_unit(arg: &str) -> WindSpeedUnit {
+    match arg {
+        "kmh" => WindSpeedUnit::Kmh,
+        "ms" => WindSpeedUnit::Ms,
+        "mph" => WindSpeedUnit::Mph,
+        "knots" => WindSpeedUnit::Kn,
+        other => {
+            error!("Unknown wind speed unit \"{}\", defaulting to kmh", other);
+            WindSpeedUnit::Kmh
+        }
+    }
+}
+
+/// The axis suffix for a given temperature unit.
+fn temperature_unit_suffix(unit: &TemperatureUnit) -> &'static str {
+    match unit {
+        TemperatureUnit::Celsius => "°C",
+        TemperatureUnit::Fahrenheit => "°F",
+    }
+}
+
+/// The axis suffix for a given wind speed unit.
+fn wind_speed_unit_suffix(unit: &WindSpeedUnit) -> &'static str {
+    match unit {
+        WindSpeedUnit::Kmh => "km/h",
+        WindSpeedUnit::Ms => "m/s",
+        WindSpeedUnit::Mph => "mph",
+        WindSpeedUnit::Kn => "kn",
+    }
+}
+
+/// The Y-axis unit suffix for the currently selected metric, honoring the
+/// user's chosen temperature and wind speed units.
+fn unit_suffix_for<'a>(metric: Metric, temperature_suffix: &'a str, wind_speed_suffix: &'a str) -> &'a str {
+    match metric {
+        Metric::TempMax => temperature_suffix,
+        Metric::WindMax => wind_speed_suffix,
+        Metric::Precipitation => "mm",
+        Metric::Humidity => "%",
+    }
+}
+
+/// One day's exported low/high temperatures, used for the `--format json` output.
+#[derive(Serialize)]
+struct DailyValue {
+    date: String,
+    low: f64,
+    high: f64,
+}
+
+/// Prints the daily low/high temperature series to stdout for headless use.
+///
+/// This is the single place that validates `format`: the `"normal"` TUI path never
+/// calls it, so any other value is either a recognized export format or a mistake
+/// the caller should be told about.
+///
+/// # Arguments
+///
+/// * `temp_data` - The `(date, low, high)` series from `extract_temperature_data`.
+/// * `format` - Either `"json"` or `"csv"`; any other value logs an error and prints nothing.
+///
+/// # Returns
+///
+/// `true` if `format` was recognized and the series was printed, `false` otherwise.
+fn print_export(temp_data: &[(NaiveDate, f64, f64)], format: &str) -> bool {
+    match format {
+        "json" => {
+            let values: Vec<DailyValue> = temp_data
+                .iter()
+                .map(|&(date, low, high)| DailyValue {
+                    date: date.format("%Y-%m-%d").to_string(),
+                    low,
+                    high,
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&values).unwrap());
+            true
+        }
+        "csv" => {
+            println!("date,low,high");
+            for &(date, low, high) in temp_data {
+                println!("{},{},{}", date.format("%Y-%m-%d"), low, high);
             }
+            true
+        }
+        other => {
+            error!("Unknown export format \"{}\"", other);
+            false
         }
     }
-    Ok(false)
 }
 
-/// Extracts daily maximum temperatures from the forecast result.
+/// Extracts the daily low and high temperatures from the forecast result.
 ///
 /// # Arguments
 ///
@@ -123,19 +493,53 @@ fn handle_events() -> io::Result<bool> {
 ///
 /// # Returns
 ///
-/// Returns a vector of tuples where each tuple contains a `NaiveDate` and a `f64` representing
-/// the date and maximum temperature for that day, respectively.
-fn extract_temperature_data(forecast: &ForecastResult) -> Vec<(NaiveDate, f64)> {
+/// Returns a vector of tuples where each tuple contains a `NaiveDate`, the daily low
+/// temperature, and the daily high temperature, in that order.
+fn extract_temperature_data(forecast: &ForecastResult) -> Vec<(NaiveDate, f64, f64)> {
     if let Some(daily) = &forecast.daily {
         daily
             .iter()
             .map(|entry| {
-                let temp = entry
+                let low = entry
+                    .values
+                    .get("temperature_2m_min")
+                    .and_then(|v| v.value.as_f64())
+                    .unwrap_or(0.0);
+                let high = entry
                     .values
                     .get("temperature_2m_max")
                     .and_then(|v| v.value.as_f64())
                     .unwrap_or(0.0);
-                (entry.date, temp)
+                (entry.date, low, high)
+            })
+            .collect()
+    } else {
+        vec![]
+    }
+}
+
+/// Extracts a single daily metric series from the forecast result.
+///
+/// # Arguments
+///
+/// * `forecast` - A reference to the `ForecastResult` containing daily weather data.
+/// * `metric` - Which daily field to extract.
+///
+/// # Returns
+///
+/// Returns a vector of tuples where each tuple contains a `NaiveDate` and the metric's
+/// value for that day.
+fn extract_metric_data(forecast: &ForecastResult, metric: Metric) -> Vec<(NaiveDate, f64)> {
+    if let Some(daily) = &forecast.daily {
+        daily
+            .iter()
+            .map(|entry| {
+                let value = entry
+                    .values
+                    .get(metric.field_name())
+                    .and_then(|v| v.value.as_f64())
+                    .unwrap_or(0.0);
+                (entry.date, value)
             })
             .collect()
     } else {
@@ -143,70 +547,186 @@ fn extract_temperature_data(forecast: &ForecastResult) -> Vec<(NaiveDate, f64)>
     }
 }
 
+/// A single line or band to plot on the chart, owning the data it renders so it can
+/// outlive the branch that built it.
+struct SeriesSpec {
+    name: String,
+    color: Color,
+    marker: symbols::Marker,
+    graph_type: GraphType,
+    data: Vec<(f64, f64)>,
+}
+
 /// Renders the user interface and displays the weather forecast chart.
 ///
 /// # Arguments
 ///
 /// * `frame` - A mutable reference to the `Frame` used for rendering.
 /// * `res` - A reference to the `ForecastResult` containing the weather data.
-fn ui(frame: &mut Frame, res: &ForecastResult) {
-    // Extract temperature data with dates
-    let temp_data = extract_temperature_data(res);
+/// * `location_label` - A human-readable label for the forecast location, shown in the chart title.
+/// * `metric` - Which daily variable to plot.
+/// * `temperature_suffix` - The Y-axis suffix for the user's chosen temperature unit.
+/// * `wind_speed_suffix` - The Y-axis suffix for the user's chosen wind speed unit.
+/// * `updated_at` - The time (HH:MM) the displayed forecast was last fetched.
+fn ui(
+    frame: &mut Frame,
+    res: &ForecastResult,
+    location_label: &str,
+    metric: Metric,
+    temperature_suffix: &str,
+    wind_speed_suffix: &str,
+    updated_at: &str,
+) {
+    let (x_labels, series, y_bounds) = match metric {
+        Metric::TempMax => {
+            // Extract temperature data with dates
+            let temp_data = extract_temperature_data(res);
 
-    // Log temperature data
-    debug!("Temperature data: {:?}", temp_data);
+            // Log temperature data
+            debug!("Temperature data: {:?}", temp_data);
 
-    // Prepare data for the chart
-    let chart_data: Vec<(f64, f64)> = temp_data
-        .iter()
-        .enumerate()
-        .map(|(i, &(_, temp))| (i as f64, temp))
-        .collect();
+            // Prepare high/low data for the chart
+            let high_data: Vec<(f64, f64)> = temp_data
+                .iter()
+                .enumerate()
+                .map(|(i, &(_, _, high))| (i as f64, high))
+                .collect();
+            let low_data: Vec<(f64, f64)> = temp_data
+                .iter()
+                .enumerate()
+                .map(|(i, &(_, low, _))| (i as f64, low))
+                .collect();
 
-    // Prepare x-axis labels with dates
-    let x_labels: Vec<String> = temp_data
-        .iter()
-        .map(|(date, _)| date.format("%m/%d").to_string())
-        .collect();
+            // Fill the area between the low and high lines with faint points so the
+            // daily temperature range reads as a band rather than two bare lines.
+            let band_data: Vec<(f64, f64)> = temp_data
+                .iter()
+                .enumerate()
+                .flat_map(|(i, &(_, low, high))| {
+                    let steps = 20;
+                    (0..=steps).map(move |step| {
+                        let t = step as f64 / steps as f64;
+                        (i as f64, low + (high - low) * t)
+                    })
+                })
+                .collect();
+
+            let x_labels: Vec<String> = temp_data
+                .iter()
+                .map(|(date, _, _)| date.format("%m/%d").to_string())
+                .collect();
+
+            let (min_temp, max_temp) = temp_data.iter().fold((f64::MAX, f64::MIN), |(min, max), &(_, low, high)| {
+                (min.min(low), max.max(high))
+            });
+            let margin = 5.0;
+
+            let series = vec![
+                SeriesSpec {
+                    name: "Range".to_string(),
+                    color: Color::DarkGray,
+                    marker: symbols::Marker::Dot,
+                    graph_type: GraphType::Scatter,
+                    data: band_data,
+                },
+                SeriesSpec {
+                    name: format!("High ({})", temperature_suffix),
+                    color: Color::Magenta,
+                    marker: symbols::Marker::Braille,
+                    graph_type: GraphType::Line,
+                    data: high_data,
+                },
+                SeriesSpec {
+                    name: format!("Low ({})", temperature_suffix),
+                    color: Color::Cyan,
+                    marker: symbols::Marker::Braille,
+                    graph_type: GraphType::Line,
+                    data: low_data,
+                },
+            ];
+
+            (x_labels, series, [min_temp - margin, max_temp + margin])
+        }
+        _ => {
+            let metric_data = extract_metric_data(res, metric);
+
+            // Log metric data
+            debug!("{:?} data: {:?}", metric, metric_data);
+
+            let chart_data: Vec<(f64, f64)> = metric_data
+                .iter()
+                .enumerate()
+                .map(|(i, &(_, value))| (i as f64, value))
+                .collect();
+
+            let x_labels: Vec<String> = metric_data
+                .iter()
+                .map(|(date, _)| date.format("%m/%d").to_string())
+                .collect();
+
+            let (min_value, max_value) = metric_data
+                .iter()
+                .fold((f64::MAX, f64::MIN), |(min, max), &(_, value)| (min.min(value), max.max(value)));
+            let margin = (max_value - min_value).max(1.0) * 0.1;
+
+            let unit = unit_suffix_for(metric, temperature_suffix, wind_speed_suffix);
+            let series = vec![SeriesSpec {
+                name: format!("{} ({})", metric.label(), unit),
+                color: metric.color(),
+                marker: symbols::Marker::Braille,
+                graph_type: GraphType::Line,
+                data: chart_data,
+            }];
+
+            (x_labels, series, [min_value - margin, max_value + margin])
+        }
+    };
 
     // Create the datasets to fill the chart with
-    let datasets = vec![Dataset::default()
-        .name("High")
-        .marker(symbols::Marker::Braille)
-        .graph_type(GraphType::Line)
-        .style(Style::default().fg(Color::Magenta))
-        .data(&chart_data)];
+    let datasets: Vec<Dataset> = series
+        .iter()
+        .map(|s| {
+            Dataset::default()
+                .name(s.name.clone())
+                .marker(s.marker)
+                .graph_type(s.graph_type)
+                .style(Style::default().fg(s.color))
+                .data(&s.data)
+        })
+        .collect();
 
     // Create the X axis and define its properties
     let x_axis = Axis::default()
         .style(Style::default().fg(Color::White))
-        .bounds([0.0, chart_data.len() as f64])
+        .bounds([0.0, x_labels.len() as f64])
         .labels(x_labels.iter().map(|s| s.into()).collect());
 
-    // Calculate y-axis bounds dynamically
-    let (min_temp, max_temp) = temp_data.iter().fold((f64::MAX, f64::MIN), |(min, max), &(_, temp)| {
-        (min.min(temp), max.max(temp))
-    });
-
-    // Add a margin for better visualization
-    let margin = 5.0;
-    let y_bounds = [min_temp - margin, max_temp + margin];
-
     // Generate y-axis labels dynamically
+    let step = ((y_bounds[1] - y_bounds[0]) / 5.0).max(1.0) as i32;
+    let unit = unit_suffix_for(metric, temperature_suffix, wind_speed_suffix);
     let y_labels: Vec<String> = (y_bounds[0] as i32..=y_bounds[1] as i32)
-        .step_by(5) // Adjust step size for better label distribution
-        .map(|v| format!("{}°F", v))
+        .step_by(step as usize)
+        .map(|v| format!("{}{}", v, unit))
         .collect();
 
     // Create the Y axis and define its properties
     let y_axis = Axis::default()
         .style(Style::default().fg(Color::White))
-        .bounds(y_bounds) // Adjust bounds to reflect Fahrenheit range
+        .bounds(y_bounds)
         .labels(y_labels.iter().map(|s| s.into()).collect());
 
     // Create the chart and link all the parts together
     let chart = Chart::new(datasets)
-        .block(Block::default().title("Forecast").borders(Borders::ALL))
+        .block(
+            Block::default()
+                .title(format!(
+                    "Forecast — {} [{}] (updated {}) (Tab to switch)",
+                    location_label,
+                    metric.label(),
+                    updated_at
+                ))
+                .borders(Borders::ALL),
+        )
         .x_axis(x_axis)
         .y_axis(y_axis);
 
@@ -217,11 +737,12 @@ fn ui(frame: &mut Frame, res: &ForecastResult) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use open_meteo_rs::forecast::{ForecastResult, ForecastResultItem};
+    use open_meteo_rs::forecast::{ForecastResult, ForecastResultDaily, ForecastResultItem};
     use std::collections::HashMap;
     use chrono::NaiveDate;
 
-    /// Tests the `extract_temperature_data` function to ensure it correctly extracts temperatures.
+    /// Tests the `extract_temperature_data` function to ensure it correctly extracts
+    /// both the daily low and high temperatures.
     #[test]
     fn test_extract_temperature_data() {
         // Create sample forecast data
@@ -240,6 +761,13 @@ mod tests {
                                 value: 82.76.into(), // Example Fahrenheit value
                             },
                         );
+                        map.insert(
+                            "temperature_2m_min".into(),
+                            ForecastResultItem {
+                                unit: Some("°F".into()),
+                                value: 68.5.into(), // Example Fahrenheit value
+                            },
+                        );
                         map
                     },
                 },
@@ -254,6 +782,13 @@ mod tests {
                                 value: 75.2.into(), // Example Fahrenheit value
                             },
                         );
+                        map.insert(
+                            "temperature_2m_min".into(),
+                            ForecastResultItem {
+                                unit: Some("°F".into()),
+                                value: 60.1.into(), // Example Fahrenheit value
+                            },
+                        );
                         map
                     },
                 },
@@ -265,10 +800,12 @@ mod tests {
         let expected = vec![
             (
                 NaiveDate::parse_from_str("2024-08-02", "%Y-%m-%d").unwrap(),
+                68.5,
                 82.76,
             ),
             (
                 NaiveDate::parse_from_str("2024-08-03", "%Y-%m-%d").unwrap(),
+                60.1,
                 75.2,
             ),
         ];
@@ -279,4 +816,89 @@ mod tests {
         // Assert the result matches the expected output
         assert_eq!(result, expected);
     }
+
+    /// Tests the `extract_metric_data` function to ensure it pulls the field named
+    /// by the given `Metric` out of each day's values.
+    #[test]
+    fn test_extract_metric_data() {
+        let forecast = ForecastResult {
+            current: None,
+            hourly: None,
+            daily: Some(vec![
+                ForecastResultDaily {
+                    date: NaiveDate::parse_from_str("2024-08-02", "%Y-%m-%d").unwrap(),
+                    values: {
+                        let mut map = HashMap::new();
+                        map.insert(
+                            "precipitation_sum".into(),
+                            ForecastResultItem {
+                                unit: Some("mm".into()),
+                                value: 3.5.into(),
+                            },
+                        );
+                        map
+                    },
+                },
+                ForecastResultDaily {
+                    date: NaiveDate::parse_from_str("2024-08-03", "%Y-%m-%d").unwrap(),
+                    values: {
+                        let mut map = HashMap::new();
+                        map.insert(
+                            "precipitation_sum".into(),
+                            ForecastResultItem {
+                                unit: Some("mm".into()),
+                                value: 0.0.into(),
+                            },
+                        );
+                        map
+                    },
+                },
+            ]),
+        };
+
+        let expected = vec![
+            (NaiveDate::parse_from_str("2024-08-02", "%Y-%m-%d").unwrap(), 3.5),
+            (NaiveDate::parse_from_str("2024-08-03", "%Y-%m-%d").unwrap(), 0.0),
+        ];
+
+        let result = extract_metric_data(&forecast, Metric::Precipitation);
+
+        assert_eq!(result, expected);
+    }
+
+    /// Tests that `parse_temperature_unit` recognizes both known values and falls
+    /// back to Fahrenheit for anything else.
+    #[test]
+    fn test_parse_temperature_unit() {
+        assert_eq!(parse_temperature_unit("celsius"), TemperatureUnit::Celsius);
+        assert_eq!(parse_temperature_unit("fahrenheit"), TemperatureUnit::Fahrenheit);
+        assert_eq!(parse_temperature_unit("kelvin"), TemperatureUnit::Fahrenheit);
+    }
+
+    /// Tests that `parse_wind_speed_unit` recognizes every known value and falls
+    /// back to km/h for anything else.
+    #[test]
+    fn test_parse_wind_speed_unit() {
+        assert_eq!(parse_wind_speed_unit("kmh"), WindSpeedUnit::Kmh);
+        assert_eq!(parse_wind_speed_unit("ms"), WindSpeedUnit::Ms);
+        assert_eq!(parse_wind_speed_unit("mph"), WindSpeedUnit::Mph);
+        assert_eq!(parse_wind_speed_unit("knots"), WindSpeedUnit::Kn);
+        assert_eq!(parse_wind_speed_unit("bogus"), WindSpeedUnit::Kmh);
+    }
+
+    /// Tests that the `DailyValue` shape used by `print_export`'s `"json"` branch
+    /// carries both the low and high temperature, not just the high.
+    #[test]
+    fn test_daily_value_json_includes_low_and_high() {
+        let value = DailyValue {
+            date: "2024-08-02".to_string(),
+            low: 68.5,
+            high: 82.76,
+        };
+
+        let json = serde_json::to_string(&value).unwrap();
+
+        assert!(json.contains("\"low\":68.5"));
+        assert!(json.contains("\"high\":82.76"));
+    }
 }
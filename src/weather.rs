@@ -1,135 +1,162 @@
-use serde::{Deserialize, Serialize};
-use reqwest::Error; // Ensure this import is here for the Error type
-
-/// Represents the units for the current weather data.
-#[derive(Deserialize, Serialize, Debug, PartialEq)]
-pub struct CurrentUnits {
-    /// The time of the weather data.
-    pub time: String,
-    /// The interval of the weather data.
-    pub interval: String,
-    /// The unit for temperature at 2 meters above ground.
-    pub temperature_2m: String,
-    /// The unit for wind speed at 10 meters above ground.
-    pub wind_speed_10m: String,
+use serde::Deserialize;
+use std::fmt;
+
+/// Error returned when resolving a location, either by place name or by IP.
+#[derive(Debug)]
+pub enum LocationError {
+    /// The underlying HTTP request or JSON decoding failed.
+    Request(reqwest::Error),
+    /// The lookup succeeded but returned no usable location.
+    NotFound(String),
 }
 
-/// Represents the current weather data.
-#[derive(Deserialize, Serialize, Debug, PartialEq)]
-pub struct Current {
-    /// The time of the weather data.
-    pub time: String,
-    /// The interval of the weather data in minutes.
-    pub interval: u32,
-    /// The temperature at 2 meters above ground in Celsius.
-    pub temperature_2m: f64,
-    /// The wind speed at 10 meters above ground in meters per second.
-    pub wind_speed_10m: f64,
+impl fmt::Display for LocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocationError::Request(e) => write!(f, "location request failed: {}", e),
+            LocationError::NotFound(query) => write!(f, "no location found for \"{}\"", query),
+        }
+    }
 }
 
-/// Represents the weather response from the API.
-#[derive(Deserialize, Serialize, Debug, PartialEq)]
-pub struct WeatherResponse {
-    /// The latitude of the location.
-    pub latitude: f64,
-    /// The longitude of the location.
-    pub longitude: f64,
-    /// The timezone of the location.
-    pub timezone: String,
-    /// The abbreviation for the timezone.
-    pub timezone_abbreviation: String,
-    /// The elevation of the location in meters.
-    pub elevation: f64,
-    /// The units for the current weather data.
-    pub current_units: CurrentUnits,
-    /// The current weather data.
-    pub current: Current,
+impl std::error::Error for LocationError {}
+
+impl From<reqwest::Error> for LocationError {
+    fn from(e: reqwest::Error) -> Self {
+        LocationError::Request(e)
+    }
 }
 
-/// Fetches the current weather data for a given latitude and longitude.
-///
-/// # Arguments
-///
-/// * `lat` - The latitude of the location.
-/// * `lon` - The longitude of the location.
+/// A single match from the Open-Meteo geocoding API.
+#[derive(Deserialize, Debug)]
+struct GeocodingResult {
+    latitude: f64,
+    longitude: f64,
+    name: String,
+    country: Option<String>,
+}
+
+/// The top-level response from the Open-Meteo geocoding API.
+#[derive(Deserialize, Debug)]
+struct GeocodingResponse {
+    results: Option<Vec<GeocodingResult>>,
+}
+
+/// The response shape returned by ipapi.co's JSON geolocation endpoint.
+#[derive(Deserialize, Debug)]
+struct IpLocationResponse {
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    city: Option<String>,
+}
+
+/// Geolocates the caller by public IP address, with no API key required.
 ///
 /// # Returns
 ///
-/// * `Ok(WeatherResponse)` - The weather data if successful.
-/// * `Err(Error)` - An error if the request fails.
+/// * `Ok((lat, lng, label))` - The coordinates of the caller's approximate location, plus
+///   the city name reported by the lookup service.
+/// * `Err(LocationError::NotFound)` - The service responded but did not include usable
+///   coordinates (e.g. it returned an error body instead of a location).
+/// * `Err(LocationError::Request)` - The request to the lookup service failed.
+pub async fn locate_by_ip() -> Result<(f64, f64, String), LocationError> {
+    let response = reqwest::get("https://ipapi.co/json").await?;
+    let parsed: IpLocationResponse = response.json().await?;
+
+    match (parsed.latitude, parsed.longitude) {
+        (Some(lat), Some(lng)) => {
+            let label = parsed.city.unwrap_or_else(|| "your location".to_string());
+            Ok((lat, lng, label))
+        }
+        _ => Err(LocationError::NotFound("IP-based lookup".to_string())),
+    }
+}
+
+/// Resolves a free-form place name (e.g. `"Lisbon, Portugal"`) to coordinates.
 ///
-/// # Examples
+/// # Arguments
 ///
-/// ```rust,no_run
-/// use weather_tui::weather::fetch_weather;
+/// * `query` - The place name to look up.
 ///
-/// #[tokio::main]
-/// async fn main() {
-///     let lat = 40.7128; // latitude for NYC
-///     let lon = -74.0060; // longitude for NYC
+/// # Returns
 ///
-///     match fetch_weather(lat, lon).await {
-///         Ok(weather) => println!("Weather data: {:?}", weather),
-///         Err(e) => eprintln!("Error fetching weather: {:?}", e),
-///     }
-/// }
-/// ```
-pub async fn fetch_weather(lat: f64, lon: f64) -> Result<WeatherResponse, Error> {
-    let url = format!(
-        "https://api.open-meteo.com/v1/forecast?latitude={}&longitude={}&current=temperature_2m,wind_speed_10m&temperature_unit=fahrenheit",
-        lat, lon
-    );
-    let response = reqwest::get(&url).await?;
-    let weather_data = response.json::<WeatherResponse>().await?;
-    Ok(weather_data)
+/// * `Ok((lat, lng, label))` - The coordinates of the best match, plus a human-readable
+///   label (the name, followed by the country when the API reports one) suitable for
+///   display in a chart title.
+/// * `Err(LocationError::NotFound)` - The query matched no known place.
+/// * `Err(LocationError::Request)` - The request to the geocoding API failed.
+pub async fn geocode(query: &str) -> Result<(f64, f64, String), LocationError> {
+    let response = reqwest::Client::new()
+        .get("https://geocoding-api.open-meteo.com/v1/search")
+        .query(&[("name", query), ("count", "1")])
+        .send()
+        .await?;
+
+    let parsed: GeocodingResponse = response.json().await?;
+
+    let result = parsed
+        .results
+        .and_then(|mut results| {
+            if results.is_empty() {
+                None
+            } else {
+                Some(results.remove(0))
+            }
+        })
+        .ok_or_else(|| LocationError::NotFound(query.to_string()))?;
+
+    let label = match result.country {
+        Some(country) => format!("{}, {}", result.name, country),
+        None => result.name,
+    };
+
+    Ok((result.latitude, result.longitude, label))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[tokio::test]
-    async fn test_fetch_weather() {
-        // Mocked data or a real API call can be used here.
-        // Here, we use a known location for testing purposes.
-        let lat = 40.7128;
-        let lon = -74.0060;
+    #[test]
+    fn test_geocoding_response_struct() {
+        // Example data matching the Open-Meteo geocoding API response shape
+        let json_data = r#"
+        {
+            "results": [
+                {
+                    "latitude": 38.7223,
+                    "longitude": -9.1393,
+                    "name": "Lisbon",
+                    "country": "Portugal"
+                }
+            ]
+        }
+        "#;
 
-        let result = fetch_weather(lat, lon).await;
+        let geocoding_response: GeocodingResponse = serde_json::from_str(json_data).unwrap();
+        let results = geocoding_response.results.unwrap();
 
-        assert!(result.is_ok());
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "Lisbon");
+        assert_eq!(results[0].country.as_deref(), Some("Portugal"));
+        assert_eq!(results[0].latitude, 38.7223);
     }
 
     #[test]
-    fn test_weather_response_struct() {
-        // Example data to test struct deserialization
+    fn test_ip_location_response_struct() {
+        // Example data matching the ipapi.co JSON response shape
         let json_data = r#"
         {
-            "latitude": 40.7128,
-            "longitude": -74.0060,
-            "timezone": "America/New_York",
-            "timezone_abbreviation": "EDT",
-            "elevation": 10.0,
-            "current_units": {
-                "time": "2024-08-01T00:00:00Z",
-                "interval": "1h",
-                "temperature_2m": "Â°C",
-                "wind_speed_10m": "m/s"
-            },
-            "current": {
-                "time": "2024-08-01T00:00:00Z",
-                "interval": 60,
-                "temperature_2m": 25.0,
-                "wind_speed_10m": 5.0
-            }
+            "latitude": 52.3676,
+            "longitude": 4.9041,
+            "city": "Amsterdam"
         }
         "#;
 
-        let weather_response: WeatherResponse = serde_json::from_str(json_data).unwrap();
+        let ip_location: IpLocationResponse = serde_json::from_str(json_data).unwrap();
 
-        assert_eq!(weather_response.latitude, 40.7128);
-        assert_eq!(weather_response.longitude, -74.0060);
-        assert_eq!(weather_response.timezone, "America/New_York");
-        assert_eq!(weather_response.current.temperature_2m, 25.0);
+        assert_eq!(ip_location.latitude, Some(52.3676));
+        assert_eq!(ip_location.longitude, Some(4.9041));
+        assert_eq!(ip_location.city.as_deref(), Some("Amsterdam"));
     }
 }